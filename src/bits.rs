@@ -0,0 +1,209 @@
+use crate::bytes::BytePoint;
+use crate::Point;
+use crate::Progress;
+use crate::Status;
+
+/// Tracks the location of parsing within a byte slice at bit granularity.
+///
+/// Bits are read MSB-first within each byte, which is how most binary
+/// wire formats (packet headers, compressed bitstreams, flag fields)
+/// pack sub-byte values.
+#[derive(Debug)]
+pub struct BitPoint<'a> {
+    /// The bytes being parsed.
+    pub s: &'a [u8],
+    /// How many bits into `s` we are.
+    pub bit_offset: usize,
+}
+
+impl<'a> BitPoint<'a> {
+    #[inline]
+    pub fn new(s: &'a [u8]) -> Self {
+        BitPoint { s, bit_offset: 0 }
+    }
+
+    #[inline]
+    pub fn fail<T>(self) -> Progress<BitPoint<'a>, T, ()> {
+        Progress {
+            point: self,
+            status: Status::Failure(()),
+        }
+    }
+
+    /// Reads `count` bits, MSB-first, advancing past them. The result is
+    /// right-aligned in the returned `u64`. `count` must be no greater
+    /// than 64.
+    pub fn take_bits(self, count: usize) -> Progress<BitPoint<'a>, u64, ()> {
+        debug_assert!(count <= 64, "can only take up to 64 bits at a time");
+
+        let remaining_bits = self.s.len() * 8 - self.bit_offset;
+        if count > remaining_bits {
+            return self.fail();
+        }
+
+        let mut bit_offset = self.bit_offset;
+        let mut value: u64 = 0;
+
+        for _ in 0..count {
+            let byte_idx = bit_offset / 8;
+            let bit_idx = bit_offset % 8;
+            let bit = (self.s[byte_idx] >> (7 - bit_idx)) & 1;
+            value = (value << 1) | u64::from(bit);
+            bit_offset += 1;
+        }
+
+        Progress {
+            point: BitPoint {
+                s: self.s,
+                bit_offset,
+            },
+            status: Status::Success(value),
+        }
+    }
+
+    /// Converts back to a byte-granular point. Only succeeds if the
+    /// current position is byte-aligned.
+    pub fn into_byte_point(self) -> Progress<BitPoint<'a>, BytePoint<'a>, ()> {
+        if self.bit_offset % 8 != 0 {
+            return self.fail();
+        }
+
+        let byte_offset = self.bit_offset / 8;
+        let byte_point = BytePoint {
+            offset: byte_offset,
+            s: &self.s[byte_offset..],
+        };
+
+        Progress {
+            point: self,
+            status: Status::Success(byte_point),
+        }
+    }
+
+    /// Alias for [`BitPoint::into_byte_point`].
+    #[inline]
+    pub fn bytes(self) -> Progress<BitPoint<'a>, BytePoint<'a>, ()> {
+        self.into_byte_point()
+    }
+}
+
+impl<'a> Point for BitPoint<'a> {
+    #[inline]
+    fn zero() -> Self {
+        BitPoint {
+            s: &[],
+            bit_offset: 0,
+        }
+    }
+}
+
+impl<'a> Copy for BitPoint<'a> {}
+impl<'a> Clone for BitPoint<'a> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a> PartialOrd for BitPoint<'a> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<'a> Ord for BitPoint<'a> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.bit_offset.cmp(&other.bit_offset)
+    }
+}
+
+impl<'a> PartialEq for BitPoint<'a> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.bit_offset.eq(&other.bit_offset)
+    }
+}
+
+impl<'a> Eq for BitPoint<'a> {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn takes_bits_across_byte_boundary() {
+        // 0b1010_1100, 0b1111_0000
+        let p = BitPoint::new(&[0b1010_1100, 0b1111_0000]);
+
+        let r = p.take_bits(4);
+        assert_eq!(
+            r,
+            Progress {
+                point: BitPoint {
+                    s: p.s,
+                    bit_offset: 4
+                },
+                status: Status::Success(0b1010),
+            }
+        );
+
+        let (p, _) = (r.point, ());
+        let r = p.take_bits(8);
+        assert_eq!(
+            r,
+            Progress {
+                point: BitPoint {
+                    s: p.s,
+                    bit_offset: 12
+                },
+                status: Status::Success(0b1100_1111),
+            }
+        );
+    }
+
+    #[test]
+    fn fails_when_not_enough_bits_remain() {
+        let p = BitPoint::new(&[0xFF]);
+
+        let r = p.take_bits(9);
+        assert_eq!(
+            r,
+            Progress {
+                point: p,
+                status: Status::Failure(()),
+            }
+        );
+    }
+
+    #[test]
+    fn converts_to_byte_point_when_aligned() {
+        let p = BitPoint::new(&[0x01, 0x02]);
+        let (p, _) = (p.take_bits(8).point, ());
+
+        let r = p.into_byte_point();
+        assert_eq!(
+            r,
+            Progress {
+                point: p,
+                status: Status::Success(BytePoint { offset: 1, s: &[0x02] }),
+            }
+        );
+    }
+
+    #[test]
+    fn fails_to_convert_when_not_aligned() {
+        let p = BitPoint::new(&[0x01, 0x02]);
+        let (p, _) = (p.take_bits(4).point, ());
+
+        let r = p.into_byte_point();
+        assert_eq!(
+            r,
+            Progress {
+                point: p,
+                status: Status::Failure(()),
+            }
+        );
+    }
+}
@@ -0,0 +1,306 @@
+use std::num::NonZeroUsize;
+
+use crate::bytes::BytePoint;
+use crate::strings::StringPoint;
+use crate::Point;
+use crate::Progress;
+use crate::Status;
+
+/// How much more input is required before a partial parse can be
+/// resolved.
+///
+/// Mirrors the "not enough input *yet*" case that plain `Status::Failure`
+/// cannot distinguish from "never going to match".
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Needed {
+    /// The exact number of additional elements required.
+    Size(NonZeroUsize),
+    /// More input is required, but how much isn't known up front.
+    Unknown,
+}
+
+/// Wraps a point so that running out of input reports [`Needed`] instead
+/// of an outright failure.
+///
+/// Use this when the full input isn't available yet (for example, it is
+/// arriving over the network in chunks): feed what has arrived so far,
+/// and if parsing reports `Needed`, wait for more bytes and retry rather
+/// than giving up.
+#[derive(Debug)]
+pub struct Partial<P> {
+    pub point: P,
+}
+
+impl<P> Partial<P> {
+    #[inline]
+    pub fn new(point: P) -> Self {
+        Partial { point }
+    }
+}
+
+impl<P: Point> Point for Partial<P> {
+    #[inline]
+    fn zero() -> Self {
+        Partial { point: P::zero() }
+    }
+}
+
+impl<P: Copy> Copy for Partial<P> {}
+impl<P: Copy> Clone for Partial<P> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<P: PartialEq> PartialEq for Partial<P> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.point.eq(&other.point)
+    }
+}
+
+impl<P: Eq> Eq for Partial<P> {}
+
+impl<P: PartialOrd> PartialOrd for Partial<P> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        self.point.partial_cmp(&other.point)
+    }
+}
+
+impl<P: Ord> Ord for Partial<P> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.point.cmp(&other.point)
+    }
+}
+
+impl<'a> Partial<BytePoint<'a>> {
+    /// Like [`crate::SlicePoint::consume`], but reports [`Needed`] rather
+    /// than an outright failure when the input simply hasn't arrived
+    /// yet.
+    #[inline]
+    pub fn consume(self, len: usize) -> Progress<Self, &'a [u8], Needed> {
+        if len == 0 {
+            return Progress {
+                point: self,
+                status: Status::Failure(Needed::Unknown),
+            };
+        }
+
+        if len <= self.point.s.len() {
+            let matched = &self.point.s[..len];
+            let rest = &self.point.s[len..];
+            Progress {
+                point: Partial {
+                    point: BytePoint {
+                        s: rest,
+                        offset: self.point.offset + len,
+                    },
+                },
+                status: Status::Success(matched),
+            }
+        } else {
+            let needed = len - self.point.s.len();
+            Progress {
+                point: self,
+                // `needed` is always > 0 here, since `len > self.point.s.len()`
+                status: Status::Failure(Needed::Size(NonZeroUsize::new(needed).unwrap())),
+            }
+        }
+    }
+}
+
+impl<'a> Partial<StringPoint<'a>> {
+    /// Like [`StringPoint::consume_to`], but reports [`Needed`] rather
+    /// than an outright failure when the input simply hasn't arrived
+    /// yet.
+    #[inline]
+    pub fn consume_to(self, len: usize) -> Progress<Self, &'a str, Needed> {
+        if len <= self.point.s.len() {
+            let matched = &self.point.s[..len];
+            let rest = &self.point.s[len..];
+            Progress {
+                point: Partial {
+                    point: StringPoint {
+                        s: rest,
+                        offset: self.point.offset + len,
+                    },
+                },
+                status: Status::Success(matched),
+            }
+        } else {
+            let needed = len - self.point.s.len();
+            Progress {
+                point: self,
+                // `needed` is always > 0 here, since `len > self.point.s.len()`
+                status: Status::Failure(Needed::Size(NonZeroUsize::new(needed).unwrap())),
+            }
+        }
+    }
+
+    /// Advances the point if it starts with the literal.
+    ///
+    /// Reports `Failure(Some(needed))` if there isn't yet enough input
+    /// to know either way, and `Failure(None)` if the input seen so far
+    /// already rules out a match — no amount of additional input can
+    /// turn that into a success, so it must not be mistaken for
+    /// [`Needed`].
+    #[inline]
+    pub fn consume_literal(self, val: &str) -> Progress<Self, &'a str, Option<Needed>> {
+        let available = self.point.s.len().min(val.len());
+        if self.point.s.as_bytes()[..available] != val.as_bytes()[..available] {
+            return Progress {
+                point: self,
+                status: Status::Failure(None),
+            };
+        }
+
+        if self.point.s.len() < val.len() {
+            let needed = val.len() - self.point.s.len();
+            return Progress {
+                point: self,
+                status: Status::Failure(Some(Needed::Size(NonZeroUsize::new(needed).unwrap()))),
+            };
+        }
+
+        match self.consume_to(val.len()) {
+            Progress {
+                point,
+                status: Status::Success(matched),
+            } => Progress {
+                point,
+                status: Status::Success(matched),
+            },
+            Progress {
+                point,
+                status: Status::Failure(needed),
+            } => Progress {
+                point,
+                status: Status::Failure(Some(needed)),
+            },
+        }
+    }
+}
+
+macro_rules! impl_number_partial {
+    ($num:ident) => {
+        paste::paste! {
+            #[doc = "Parses a `" $num "` in little-endian encoding, reporting [`Needed`] if not enough input has arrived yet."]
+            #[inline]
+            pub fn [<$num _le>](self) -> Progress<Self, $num, Needed> {
+                self
+                    .consume(::std::mem::size_of::<$num>())
+                    .map(|n| {
+                        // unwrap cannot fail since n.len() is always at least as big
+                        // as the number type, because `consume` consumed at least
+                        // that many bytes if we end up here
+                        $num::from_le_bytes(::std::convert::TryInto::try_into(n).unwrap())
+                    })
+            }
+
+            #[doc = "Parses a `" $num "` in big-endian encoding, reporting [`Needed`] if not enough input has arrived yet."]
+            #[inline]
+            pub fn [<$num _be>](self) -> Progress<Self, $num, Needed> {
+                self
+                    .consume(::std::mem::size_of::<$num>())
+                    .map(|n| {
+                        // unwrap cannot fail since n.len() is always at least as big
+                        // as the number type, because `consume` consumed at least
+                        // that many bytes if we end up here
+                        $num::from_be_bytes(::std::convert::TryInto::try_into(n).unwrap())
+                    })
+            }
+        }
+    };
+
+    ($ty:ident $($tys:ident)*) => {
+        impl_number_partial!($ty);
+        impl_number_partial!($($tys)*);
+    };
+}
+
+impl<'a> Partial<BytePoint<'a>> {
+    impl_number_partial!(
+        u8 u16 u32 u64 u128
+        i8 i16 i32 i64 i128
+        f32 f64
+    );
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::SlicePoint;
+
+    #[test]
+    fn consume_reports_needed_when_short() {
+        let p = Partial::new(BytePoint { offset: 0, s: &[0x01, 0x02] });
+
+        let r = p.consume(5);
+        assert_eq!(
+            r,
+            Progress {
+                point: p,
+                status: Status::Failure(Needed::Size(NonZeroUsize::new(3).unwrap())),
+            }
+        );
+    }
+
+    #[test]
+    fn consume_succeeds_with_enough_input() {
+        let p = Partial::new(BytePoint { offset: 0, s: &[0x01, 0x02, 0x03] });
+
+        let r = p.consume(2);
+        assert_eq!(
+            r,
+            Progress {
+                point: Partial::new(SlicePoint { offset: 2, s: &[0x03] }),
+                status: Status::Success(&[0x01, 0x02][..]),
+            }
+        );
+    }
+
+    #[test]
+    fn consume_literal_reports_definite_mismatch() {
+        let p = Partial::new(StringPoint { offset: 0, s: "xyz" });
+
+        let r = p.consume_literal("hello");
+        assert_eq!(
+            r,
+            Progress {
+                point: p,
+                status: Status::Failure(None),
+            }
+        );
+    }
+
+    #[test]
+    fn consume_literal_reports_needed_on_matching_prefix() {
+        let p = Partial::new(StringPoint { offset: 0, s: "he" });
+
+        let r = p.consume_literal("hello");
+        assert_eq!(
+            r,
+            Progress {
+                point: p,
+                status: Status::Failure(Some(Needed::Size(NonZeroUsize::new(3).unwrap()))),
+            }
+        );
+    }
+
+    #[test]
+    fn u32_le_reports_needed() {
+        let p = Partial::new(BytePoint { offset: 0, s: &[0x01, 0x02] });
+
+        let r = p.u32_le();
+        assert_eq!(
+            r,
+            Progress {
+                point: p,
+                status: Status::Failure(Needed::Size(NonZeroUsize::new(2).unwrap())),
+            }
+        );
+    }
+}
@@ -73,6 +73,41 @@ impl<'a, T: 'a> SlicePoint<'a, T> {
             self.fail()
         }
     }
+
+    /// Consumes the maximal prefix of elements for which `pred` returns
+    /// `true`. Fails if no elements match; see
+    /// [`SlicePoint::take_while0`] to allow an empty match.
+    #[inline]
+    pub fn take_while(self, pred: impl Fn(&T) -> bool) -> Progress<SlicePoint<'a, T>, &'a [T], ()> {
+        let len = self.s.iter().take_while(|t| pred(t)).count();
+        self.consume(len)
+    }
+
+    /// Like [`SlicePoint::take_while`], but succeeds (with an empty
+    /// match) even if no elements match.
+    #[inline]
+    pub fn take_while0(self, pred: impl Fn(&T) -> bool) -> Progress<SlicePoint<'a, T>, &'a [T], ()> {
+        let len = self.s.iter().take_while(|t| pred(t)).count();
+        self.success(len)
+    }
+
+    /// Consumes the maximal prefix of elements for which `pred` returns
+    /// `false`. Fails if no elements match.
+    #[inline]
+    pub fn take_till(self, pred: impl Fn(&T) -> bool) -> Progress<SlicePoint<'a, T>, &'a [T], ()> {
+        self.take_while(|t| !pred(t))
+    }
+}
+
+impl<'a, T: PartialEq> SlicePoint<'a, T> {
+    /// Consumes a single element if it is present in `set`.
+    #[inline]
+    pub fn one_of(self, set: &[T]) -> Progress<SlicePoint<'a, T>, &'a T, ()> {
+        match self.s.first() {
+            Some(t) if set.contains(t) => self.success(1).map(|matched| &matched[0]),
+            _ => self.fail(),
+        }
+    }
 }
 
 impl<'a, T> Point for SlicePoint<'a, T> {
@@ -0,0 +1,198 @@
+use crate::bytes::ParseNumber;
+use crate::Point;
+use crate::Progress;
+
+/// Wraps a point together with a piece of state that travels alongside
+/// it as parsing advances.
+///
+/// Use this to thread context through a grammar that `ParseMaster` alone
+/// doesn't carry, such as a symbol table, configuration flags, or a
+/// nesting counter — for example, a length-prefixed record whose
+/// previously parsed count governs how many times a following loop
+/// should run.
+#[derive(Debug)]
+pub struct Stateful<P, S> {
+    pub point: P,
+    pub state: S,
+}
+
+impl<P, S> Stateful<P, S> {
+    #[inline]
+    pub fn new(point: P, state: S) -> Self {
+        Stateful { point, state }
+    }
+
+    /// Runs `f` against the inner point, re-wrapping the result with the
+    /// unchanged state. This is how `consume_*`/`tag`-style operations
+    /// on the inner point are threaded through a `Stateful` point.
+    #[inline]
+    pub fn with_point<T, E>(self, f: impl FnOnce(P) -> Progress<P, T, E>) -> Progress<Self, T, E> {
+        let Progress { point, status } = f(self.point);
+        Progress {
+            point: Stateful {
+                point,
+                state: self.state,
+            },
+            status,
+        }
+    }
+
+    /// Reads the current state.
+    #[inline]
+    pub fn state(&self) -> &S {
+        &self.state
+    }
+
+    /// Mutates the state in place as the point advances.
+    #[inline]
+    pub fn state_mut(&mut self) -> &mut S {
+        &mut self.state
+    }
+
+    /// Replaces the state, keeping the inner point unchanged.
+    #[inline]
+    pub fn with_state(self, state: S) -> Self {
+        Stateful {
+            point: self.point,
+            state,
+        }
+    }
+
+    /// Derives a new state from the current one, keeping the inner point
+    /// unchanged.
+    #[inline]
+    pub fn map_state(self, f: impl FnOnce(S) -> S) -> Self {
+        Stateful {
+            point: self.point,
+            state: f(self.state),
+        }
+    }
+}
+
+impl<P: Point, S: Copy + Default> Point for Stateful<P, S> {
+    #[inline]
+    fn zero() -> Self {
+        Stateful {
+            point: P::zero(),
+            state: S::default(),
+        }
+    }
+}
+
+impl<P: Copy, S: Copy> Copy for Stateful<P, S> {}
+impl<P: Copy, S: Copy> Clone for Stateful<P, S> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<P: PartialEq, S> PartialEq for Stateful<P, S> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.point.eq(&other.point)
+    }
+}
+
+impl<P: Eq, S> Eq for Stateful<P, S> {}
+
+impl<P: PartialOrd, S> PartialOrd for Stateful<P, S> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        self.point.partial_cmp(&other.point)
+    }
+}
+
+impl<P: Ord, S> Ord for Stateful<P, S> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.point.cmp(&other.point)
+    }
+}
+
+macro_rules! impl_number_stateful {
+    ($num:ident) => {
+        paste::paste! {
+            #[inline]
+            fn [<$num _le>](self) -> Progress<Self, $num, ()> {
+                self.with_point(|p| p.[<$num _le>]())
+            }
+
+            #[inline]
+            fn [<$num _be>](self) -> Progress<Self, $num, ()> {
+                self.with_point(|p| p.[<$num _be>]())
+            }
+        }
+    };
+
+    ($ty:ident $($tys:ident)*) => {
+        impl_number_stateful!($ty);
+        impl_number_stateful!($($tys)*);
+    };
+}
+
+impl<P, S> ParseNumber for Stateful<P, S>
+where
+    P: ParseNumber,
+    S: Copy + Default,
+{
+    impl_number_stateful!(
+        u8 u16 u32 u64 u128
+        i8 i16 i32 i64 i128
+        f32 f64
+    );
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::bytes::BytePoint;
+    use crate::Status;
+
+    #[test]
+    fn with_point_threads_state_through() {
+        let p = Stateful::new(BytePoint { offset: 0, s: &[0x01, 0x02] }, 42u32);
+
+        let r = p.with_point(|p| p.u16_le());
+        assert_eq!(
+            r,
+            Progress {
+                point: Stateful::new(BytePoint { offset: 2, s: &[] }, 42u32),
+                status: Status::Success(0x02_01_u16),
+            }
+        );
+        // `Stateful` equality ignores the state, so check it separately.
+        assert_eq!(*r.point.state(), 42);
+    }
+
+    #[test]
+    fn state_can_be_read_and_updated_as_the_point_advances() {
+        let p = Stateful::new(BytePoint { offset: 0, s: &[0x01, 0x02] }, 0u32);
+
+        // Parse a byte (a length prefix, say) and fold it into the state.
+        let r = p.with_point(|p| p.u8_le());
+        let count = match r.status {
+            Status::Success(count) => count,
+            Status::Failure(()) => unreachable!(),
+        };
+        let mut p = r.point.map_state(|total| total + u32::from(count));
+        assert_eq!(*p.state(), 1);
+
+        *p.state_mut() += 41;
+        assert_eq!(*p.state(), 42);
+    }
+
+    #[test]
+    fn parse_number_is_forwarded() {
+        let p = Stateful::new(BytePoint { offset: 0, s: &[0x01, 0x02] }, "unchanged");
+
+        let r = p.u16_le();
+        assert_eq!(
+            r,
+            Progress {
+                point: Stateful::new(BytePoint { offset: 2, s: &[] }, "unchanged"),
+                status: Status::Success(0x02_01_u16),
+            }
+        );
+    }
+}
@@ -3,6 +3,9 @@ use std::cmp::Ordering;
 use crate::Point;
 use crate::Progress;
 
+mod num;
+pub use self::num::ParseNumberText;
+
 /// Matches a literal string to a specific type, usually an enum.
 pub type Identifier<'a, T> = (&'a str, T);
 
@@ -96,6 +99,31 @@ impl<'a> StringPoint<'a> {
         }
     }
 
+    /// Advances the point if it starts with the literal, ignoring ASCII
+    /// case.
+    #[inline]
+    pub fn consume_literal_caseless(self, val: &str) -> Progress<StringPoint<'a>, &'a str, ()> {
+        match self.s.get(..val.len()) {
+            Some(candidate) if candidate.eq_ignore_ascii_case(val) => self.success(val.len()),
+            _ => self.fail(),
+        }
+    }
+
+    /// Consumes the maximal prefix of characters for which `pred`
+    /// returns `true`. Fails if no characters match.
+    #[inline]
+    pub fn take_while(self, pred: impl Fn(char) -> bool) -> Progress<StringPoint<'a>, &'a str, ()> {
+        let len = self.s.find(|c| !pred(c)).unwrap_or_else(|| self.s.len());
+        self.consume_to(if len > 0 { Some(len) } else { None })
+    }
+
+    /// Consumes the maximal prefix of characters for which `pred`
+    /// returns `false`. Fails if no characters match.
+    #[inline]
+    pub fn take_till(self, pred: impl Fn(char) -> bool) -> Progress<StringPoint<'a>, &'a str, ()> {
+        self.take_while(|c| !pred(c))
+    }
+
     /// Iterates through the identifiers and advances the point on the
     /// first matching identifier.
     #[inline]
@@ -251,6 +279,81 @@ mod tests {
         );
     }
 
+    #[test]
+    fn string_consume_literal_caseless() {
+        let pt = StringPoint::new("HeLLo world");
+
+        let r = pt.consume_literal_caseless("hello");
+        assert_eq!(
+            r,
+            Progress {
+                point: StringPoint {
+                    s: " world",
+                    offset: 5
+                },
+                status: Status::Success("HeLLo")
+            }
+        );
+
+        let r = pt.consume_literal_caseless("goodbye");
+        assert_eq!(
+            r,
+            Progress {
+                point: StringPoint {
+                    s: "HeLLo world",
+                    offset: 0
+                },
+                status: Status::Failure(())
+            }
+        );
+    }
+
+    #[test]
+    fn string_take_while() {
+        let pt = StringPoint::new("123abc");
+
+        let r = pt.take_while(|c| c.is_ascii_digit());
+        assert_eq!(
+            r,
+            Progress {
+                point: StringPoint {
+                    s: "abc",
+                    offset: 3
+                },
+                status: Status::Success("123")
+            }
+        );
+
+        let r = pt.take_while(|c| c.is_ascii_alphabetic());
+        assert_eq!(
+            r,
+            Progress {
+                point: StringPoint {
+                    s: "123abc",
+                    offset: 0
+                },
+                status: Status::Failure(())
+            }
+        );
+    }
+
+    #[test]
+    fn string_take_till() {
+        let pt = StringPoint::new("abc123");
+
+        let r = pt.take_till(|c| c.is_ascii_digit());
+        assert_eq!(
+            r,
+            Progress {
+                point: StringPoint {
+                    s: "123",
+                    offset: 3
+                },
+                status: Status::Success("abc")
+            }
+        );
+    }
+
     #[test]
     fn string_consume_identifier() {
         let pt = StringPoint::new("hello world");
@@ -0,0 +1,339 @@
+use crate::strings::StringPoint;
+use crate::Progress;
+
+/// Trait defining simple ASCII-text parsers for numeric primitives, the
+/// text counterpart to [`crate::bytes::ParseNumber`]'s binary encodings.
+pub trait ParseNumberText: Sized {
+    /// Parses an unsigned decimal integer, failing on overflow.
+    fn dec_uint<N: Digits>(self) -> Progress<Self, N, ()>;
+    /// Parses an optionally-signed decimal integer, failing on overflow.
+    fn dec_int<N: Digits + Signed>(self) -> Progress<Self, N, ()>;
+    /// Parses an unsigned hexadecimal integer, failing on overflow.
+    fn hex_uint<N: Digits>(self) -> Progress<Self, N, ()>;
+    /// Parses a floating-point number: an optional sign, an integer
+    /// and/or fractional part, and an optional `e`/`E` exponent.
+    fn float<N: std::str::FromStr>(self) -> Progress<Self, N, ()>;
+}
+
+/// A primitive integer type that can be built up one digit at a time,
+/// failing rather than wrapping on overflow.
+pub trait Digits: Copy {
+    const ZERO: Self;
+
+    fn from_digit(digit: u32) -> Self;
+    fn checked_mul_radix(self, radix: Self) -> Option<Self>;
+    fn checked_add_digit(self, digit: Self) -> Option<Self>;
+}
+
+/// A [`Digits`] type that can also represent negative values.
+pub trait Signed: Digits {
+    /// Subtracts a single digit's value, used to accumulate a negative
+    /// number in the negative domain so that a type's minimum value
+    /// (whose magnitude has no positive representation) round-trips.
+    fn checked_sub_digit(self, digit: Self) -> Option<Self>;
+}
+
+macro_rules! impl_digits {
+    ($($ty:ident),* $(,)?) => {
+        $(
+            impl Digits for $ty {
+                const ZERO: Self = 0;
+
+                #[inline]
+                fn from_digit(digit: u32) -> Self {
+                    digit as $ty
+                }
+
+                #[inline]
+                fn checked_mul_radix(self, radix: Self) -> Option<Self> {
+                    self.checked_mul(radix)
+                }
+
+                #[inline]
+                fn checked_add_digit(self, digit: Self) -> Option<Self> {
+                    self.checked_add(digit)
+                }
+            }
+        )*
+    };
+}
+
+impl_digits!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+macro_rules! impl_signed {
+    ($($ty:ident),* $(,)?) => {
+        $(
+            impl Signed for $ty {
+                #[inline]
+                fn checked_sub_digit(self, digit: Self) -> Option<Self> {
+                    self.checked_sub(digit)
+                }
+            }
+        )*
+    };
+}
+
+impl_signed!(i8, i16, i32, i64, i128, isize);
+
+/// Scans the maximal prefix of `s` that consists of digits valid in
+/// `radix`, accumulating them into `N`. Returns the parsed value and the
+/// number of bytes consumed, or `None` if no digits matched or the
+/// accumulation overflowed.
+fn accumulate_digits<N: Digits>(s: &str, radix: u32) -> Option<(N, usize)> {
+    let mut value = N::ZERO;
+    let mut len = 0;
+
+    for c in s.chars() {
+        let digit = match c.to_digit(radix) {
+            Some(d) => d,
+            None => break,
+        };
+
+        value = value
+            .checked_mul_radix(N::from_digit(radix))?
+            .checked_add_digit(N::from_digit(digit))?;
+        len += c.len_utf8();
+    }
+
+    if len == 0 {
+        None
+    } else {
+        Some((value, len))
+    }
+}
+
+/// Like [`accumulate_digits`], but accumulates in the negative domain
+/// (subtracting each digit from zero instead of adding it) so that a
+/// type's minimum value, whose magnitude has no positive representation,
+/// can still be parsed.
+fn accumulate_negative_digits<N: Digits + Signed>(s: &str, radix: u32) -> Option<(N, usize)> {
+    let mut value = N::ZERO;
+    let mut len = 0;
+
+    for c in s.chars() {
+        let digit = match c.to_digit(radix) {
+            Some(d) => d,
+            None => break,
+        };
+
+        value = value
+            .checked_mul_radix(N::from_digit(radix))?
+            .checked_sub_digit(N::from_digit(digit))?;
+        len += c.len_utf8();
+    }
+
+    if len == 0 {
+        None
+    } else {
+        Some((value, len))
+    }
+}
+
+impl<'a> ParseNumberText for StringPoint<'a> {
+    fn dec_uint<N: Digits>(self) -> Progress<Self, N, ()> {
+        match accumulate_digits::<N>(self.s, 10) {
+            Some((value, len)) => self.consume_to(Some(len)).map(|_| value),
+            None => self.fail(),
+        }
+    }
+
+    fn dec_int<N: Digits + Signed>(self) -> Progress<Self, N, ()> {
+        let (negative, sign_len, rest) = match self.s.as_bytes().first() {
+            Some(b'-') => (true, 1, &self.s[1..]),
+            Some(b'+') => (false, 1, &self.s[1..]),
+            _ => (false, 0, self.s),
+        };
+
+        // Accumulate in whichever domain the sign points into: negative
+        // numbers are built up by subtracting digits from zero rather
+        // than negating a positive accumulation at the end, so that a
+        // type's minimum value (e.g. i8::MIN, whose magnitude 128 has no
+        // positive i8 representation) can still be parsed.
+        let result = if negative {
+            accumulate_negative_digits::<N>(rest, 10)
+        } else {
+            accumulate_digits::<N>(rest, 10)
+        };
+
+        match result {
+            Some((value, digit_len)) => self.consume_to(Some(sign_len + digit_len)).map(|_| value),
+            None => self.fail(),
+        }
+    }
+
+    fn hex_uint<N: Digits>(self) -> Progress<Self, N, ()> {
+        match accumulate_digits::<N>(self.s, 16) {
+            Some((value, len)) => self.consume_to(Some(len)).map(|_| value),
+            None => self.fail(),
+        }
+    }
+
+    fn float<N: std::str::FromStr>(self) -> Progress<Self, N, ()> {
+        let bytes = self.s.as_bytes();
+        let mut i = 0;
+
+        if matches!(bytes.first(), Some(b'+') | Some(b'-')) {
+            i += 1;
+        }
+
+        let int_start = i;
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+        let has_int_part = i > int_start;
+
+        let mut has_frac_part = false;
+        if bytes.get(i) == Some(&b'.') {
+            let mut j = i + 1;
+            while j < bytes.len() && bytes[j].is_ascii_digit() {
+                j += 1;
+            }
+            if j > i + 1 {
+                has_frac_part = true;
+                i = j;
+            }
+        }
+
+        if !has_int_part && !has_frac_part {
+            return self.fail();
+        }
+
+        if matches!(bytes.get(i), Some(b'e') | Some(b'E')) {
+            let mut j = i + 1;
+            if matches!(bytes.get(j), Some(b'+') | Some(b'-')) {
+                j += 1;
+            }
+            let exp_start = j;
+            while j < bytes.len() && bytes[j].is_ascii_digit() {
+                j += 1;
+            }
+            if j > exp_start {
+                i = j;
+            }
+        }
+
+        match self.s[..i].parse() {
+            Ok(value) => self.consume_to(Some(i)).map(|_| value),
+            Err(_) => self.fail(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Status;
+
+    #[test]
+    fn parses_dec_uint() {
+        let p = StringPoint::new("123abc");
+
+        let r = p.dec_uint::<u32>();
+        assert_eq!(
+            r,
+            Progress {
+                point: StringPoint { s: "abc", offset: 3 },
+                status: Status::Success(123u32),
+            }
+        );
+    }
+
+    #[test]
+    fn fails_dec_uint_on_overflow() {
+        let p = StringPoint::new("999");
+
+        let r = p.dec_uint::<u8>();
+        assert_eq!(
+            r,
+            Progress {
+                point: p,
+                status: Status::Failure(()),
+            }
+        );
+    }
+
+    #[test]
+    fn parses_dec_int_with_sign() {
+        let p = StringPoint::new("-42rest");
+
+        let r = p.dec_int::<i32>();
+        assert_eq!(
+            r,
+            Progress {
+                point: StringPoint {
+                    s: "rest",
+                    offset: 3
+                },
+                status: Status::Success(-42i32),
+            }
+        );
+    }
+
+    #[test]
+    fn parses_dec_int_minimum_values() {
+        let p = StringPoint::new("-128");
+        let r = p.dec_int::<i8>();
+        assert_eq!(
+            r,
+            Progress {
+                point: StringPoint { s: "", offset: 4 },
+                status: Status::Success(i8::MIN),
+            }
+        );
+
+        let p = StringPoint::new("-2147483648");
+        let r = p.dec_int::<i32>();
+        assert_eq!(
+            r,
+            Progress {
+                point: StringPoint { s: "", offset: 11 },
+                status: Status::Success(i32::MIN),
+            }
+        );
+    }
+
+    #[test]
+    fn parses_hex_uint() {
+        let p = StringPoint::new("1F2g");
+
+        let r = p.hex_uint::<u32>();
+        assert_eq!(
+            r,
+            Progress {
+                point: StringPoint { s: "g", offset: 3 },
+                status: Status::Success(0x1F2u32),
+            }
+        );
+    }
+
+    #[test]
+    fn parses_float() {
+        let p = StringPoint::new("-3.14e2rest");
+
+        let r = p.float::<f64>();
+        assert_eq!(
+            r,
+            Progress {
+                point: StringPoint {
+                    s: "rest",
+                    offset: 7
+                },
+                status: Status::Success(-314.0f64),
+            }
+        );
+    }
+
+    #[test]
+    fn fails_float_on_no_digits() {
+        let p = StringPoint::new("abc");
+
+        let r = p.float::<f64>();
+        assert_eq!(
+            r,
+            Progress {
+                point: p,
+                status: Status::Failure(()),
+            }
+        );
+    }
+}